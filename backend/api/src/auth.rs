@@ -0,0 +1,249 @@
+//! Stellar keypair signature auth.
+//!
+//! Mutating endpoints that act on behalf of a `stellar_address` (publish,
+//! publisher creation, ...) require the request to be signed by the holder
+//! of that address's secret key, modeled on HTTP-signature auth: the
+//! canonical string is `METHOD\nPATH\nTIMESTAMP\nNONCE\nSHA256(body)`,
+//! signed with ed25519 and sent as headers, and verified here against the
+//! ed25519 public key StrKey-decoded from the claimed address.
+//!
+//! The timestamp alone only bounds *how long* a captured request can be
+//! replayed, not *whether* it can be — anyone who intercepts a valid
+//! signed request can resend it verbatim until it expires. [`NonceCache`]
+//! closes that gap by remembering every `x-nonce` it has already accepted
+//! within the clock-skew window and rejecting repeats.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use axum::{
+    async_trait,
+    extract::{FromRequest, Request},
+    http::{HeaderMap, StatusCode},
+};
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use sha2::{Digest, Sha256};
+
+use crate::state::AppState;
+
+/// Requests older (or newer, to tolerate clock skew) than this are
+/// rejected, bounding the window an intercepted signature can be replayed.
+const MAX_CLOCK_SKEW_SECS: i64 = 300;
+
+/// Tracks nonces already accepted within the replay window so a captured
+/// `(signature, nonce)` pair can't be resent. Entries are keyed by nonce
+/// and store the request timestamp they arrived with, so expiry can be
+/// swept using the same clock-skew window `from_request` already enforces
+/// — anything older than that window could never pass the timestamp check
+/// anyway, so it's safe to forget.
+#[derive(Default)]
+pub struct NonceCache {
+    seen: Mutex<HashMap<String, i64>>,
+}
+
+impl NonceCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records `nonce` as used at `timestamp`, rejecting it if it has
+    /// already been seen inside the current clock-skew window. Also sweeps
+    /// out entries that have aged past the window so the cache doesn't
+    /// grow without bound.
+    fn check_and_remember(&self, nonce: &str, timestamp: i64) -> Result<(), AuthError> {
+        let mut seen = self.seen.lock().expect("nonce cache mutex poisoned");
+        seen.retain(|_, seen_at| (timestamp - *seen_at).abs() <= MAX_CLOCK_SKEW_SECS);
+
+        if seen.contains_key(nonce) {
+            return Err(AuthError::ReplayedNonce);
+        }
+        seen.insert(nonce.to_string(), timestamp);
+        Ok(())
+    }
+}
+
+/// A request body that has already been authenticated as originating from
+/// the holder of `stellar_address`'s secret key. Extract this instead of
+/// `Json<T>` on any endpoint that mutates state under a claimed address.
+pub struct Signed<T> {
+    pub address: String,
+    pub body: T,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AuthError {
+    #[error("missing signature headers")]
+    MissingHeaders,
+    #[error("timestamp expired or not yet valid")]
+    ExpiredTimestamp,
+    #[error("malformed stellar address")]
+    BadAddress,
+    #[error("signature verification failed")]
+    BadSignature,
+    #[error("invalid request body")]
+    BadBody,
+    #[error("nonce already used")]
+    ReplayedNonce,
+}
+
+impl axum::response::IntoResponse for AuthError {
+    fn into_response(self) -> axum::response::Response {
+        (StatusCode::UNAUTHORIZED, self.to_string()).into_response()
+    }
+}
+
+#[async_trait]
+impl<T> FromRequest<AppState> for Signed<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    type Rejection = AuthError;
+
+    async fn from_request(req: Request, state: &AppState) -> Result<Self, Self::Rejection> {
+        let method = req.method().clone();
+        let path = req.uri().path().to_string();
+        let headers = req.headers().clone();
+
+        let address = header_str(&headers, "x-stellar-address").ok_or(AuthError::MissingHeaders)?;
+        let signature_b64 = header_str(&headers, "x-signature").ok_or(AuthError::MissingHeaders)?;
+        let timestamp: i64 = header_str(&headers, "x-timestamp")
+            .and_then(|v| v.parse().ok())
+            .ok_or(AuthError::MissingHeaders)?;
+        let nonce = header_str(&headers, "x-nonce").ok_or(AuthError::MissingHeaders)?;
+
+        let now = chrono::Utc::now().timestamp();
+        if (now - timestamp).abs() > MAX_CLOCK_SKEW_SECS {
+            return Err(AuthError::ExpiredTimestamp);
+        }
+
+        let body_bytes = axum::body::to_bytes(req.into_body(), usize::MAX)
+            .await
+            .map_err(|_| AuthError::BadBody)?;
+        let body_hash = hex::encode(Sha256::digest(&body_bytes));
+
+        let canonical = format!("{method}\n{path}\n{timestamp}\n{nonce}\n{body_hash}");
+        verify_signature(&address, &signature_b64, canonical.as_bytes())?;
+
+        // Only remember the nonce once the signature over it has already
+        // checked out — otherwise an attacker could burn a victim's nonce
+        // with a garbage signature and lock out their real request.
+        state.nonce_cache.check_and_remember(&nonce, timestamp)?;
+
+        // Endpoints with nothing to submit (e.g. yank/unyank) still sign an
+        // empty body, but `serde_json::from_slice` rejects a zero-length
+        // input outright — fall back to `null` so `Signed<()>` works.
+        let body: T = if body_bytes.is_empty() {
+            serde_json::from_value(serde_json::Value::Null).map_err(|_| AuthError::BadBody)?
+        } else {
+            serde_json::from_slice(&body_bytes).map_err(|_| AuthError::BadBody)?
+        };
+
+        Ok(Signed { address, body })
+    }
+}
+
+fn header_str(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers.get(name)?.to_str().ok().map(str::to_owned)
+}
+
+/// Decodes `address` as a Stellar StrKey ed25519 public key (`G...`) and
+/// checks `signature_b64` against `message` under it.
+fn verify_signature(address: &str, signature_b64: &str, message: &[u8]) -> Result<(), AuthError> {
+    let public_key_bytes =
+        stellar_strkey::ed25519::PublicKey::from_string(address).map_err(|_| AuthError::BadAddress)?;
+    let verifying_key =
+        VerifyingKey::from_bytes(&public_key_bytes.0).map_err(|_| AuthError::BadAddress)?;
+
+    let sig_bytes = base64::decode(signature_b64).map_err(|_| AuthError::BadSignature)?;
+    let signature = Signature::from_slice(&sig_bytes).map_err(|_| AuthError::BadSignature)?;
+
+    verifying_key
+        .verify(message, &signature)
+        .map_err(|_| AuthError::BadSignature)
+}
+
+#[cfg(test)]
+mod tests {
+    use ed25519_dalek::{Signer, SigningKey};
+
+    use super::*;
+
+    fn keypair() -> (SigningKey, String) {
+        let signing_key = SigningKey::generate(&mut rand::rngs::OsRng);
+        let address = stellar_strkey::ed25519::PublicKey(signing_key.verifying_key().to_bytes())
+            .to_string();
+        (signing_key, address)
+    }
+
+    fn sign(signing_key: &SigningKey, message: &[u8]) -> String {
+        base64::encode(signing_key.sign(message).to_bytes())
+    }
+
+    #[test]
+    fn valid_signature_passes() {
+        let (signing_key, address) = keypair();
+        let message = b"POST\n/contracts\n1000\nnonce-1\nbodyhash";
+        let signature = sign(&signing_key, message);
+
+        assert!(verify_signature(&address, &signature, message).is_ok());
+    }
+
+    #[test]
+    fn tampered_body_fails() {
+        let (signing_key, address) = keypair();
+        let signature = sign(&signing_key, b"POST\n/contracts\n1000\nnonce-1\nbodyhash");
+
+        let tampered = b"POST\n/contracts\n1000\nnonce-1\ndifferenthash";
+        assert!(matches!(
+            verify_signature(&address, &signature, tampered),
+            Err(AuthError::BadSignature)
+        ));
+    }
+
+    #[test]
+    fn wrong_key_fails() {
+        let (signing_key, _address) = keypair();
+        let (_other_key, other_address) = keypair();
+        let message = b"POST\n/contracts\n1000\nnonce-1\nbodyhash";
+        let signature = sign(&signing_key, message);
+
+        assert!(matches!(
+            verify_signature(&other_address, &signature, message),
+            Err(AuthError::BadSignature)
+        ));
+    }
+
+    #[test]
+    fn malformed_address_is_rejected() {
+        let (signing_key, _address) = keypair();
+        let message = b"POST\n/contracts\n1000\nnonce-1\nbodyhash";
+        let signature = sign(&signing_key, message);
+
+        assert!(matches!(
+            verify_signature("not-a-stellar-address", &signature, message),
+            Err(AuthError::BadAddress)
+        ));
+    }
+
+    #[test]
+    fn first_use_of_a_nonce_is_accepted_and_replay_is_rejected() {
+        let cache = NonceCache::new();
+        assert!(cache.check_and_remember("nonce-1", 1_000).is_ok());
+
+        assert!(matches!(
+            cache.check_and_remember("nonce-1", 1_010),
+            Err(AuthError::ReplayedNonce)
+        ));
+    }
+
+    #[test]
+    fn nonces_outside_the_clock_skew_window_are_forgotten() {
+        let cache = NonceCache::new();
+        assert!(cache.check_and_remember("nonce-1", 1_000).is_ok());
+
+        // Far enough past the skew window that "nonce-1" has aged out and
+        // could never pass the timestamp check again anyway.
+        let later = 1_000 + MAX_CLOCK_SKEW_SECS + 1;
+        assert!(cache.check_and_remember("nonce-1", later).is_ok());
+    }
+}
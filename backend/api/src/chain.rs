@@ -0,0 +1,205 @@
+//! Minimal Stellar RPC client used to look up the WASM actually deployed
+//! behind a contract, so the registry can check its claims against the
+//! chain instead of trusting whatever a publisher uploads.
+
+use serde::Deserialize;
+use stellar_xdr::curr::{
+    ContractDataDurability, ContractExecutable, Hash, LedgerEntryData, LedgerKey,
+    LedgerKeyContractCode, LedgerKeyContractData, Limits, ReadXdr, ScAddress, ScVal, WriteXdr,
+};
+
+/// Networks the registry knows how to resolve an RPC endpoint for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StellarNetwork {
+    Mainnet,
+    Testnet,
+    Futurenet,
+}
+
+impl StellarNetwork {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "mainnet" | "public" => Some(Self::Mainnet),
+            "testnet" => Some(Self::Testnet),
+            "futurenet" => Some(Self::Futurenet),
+            _ => None,
+        }
+    }
+}
+
+/// Thin wrapper around a soroban-rpc JSON-RPC endpoint.
+#[derive(Debug, Clone)]
+pub struct StellarRpcClient {
+    http: reqwest::Client,
+    mainnet_url: String,
+    testnet_url: String,
+    futurenet_url: String,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ChainError {
+    #[error("unknown network: {0}")]
+    UnknownNetwork(String),
+    #[error("contract {0} not found on chain")]
+    ContractNotFound(String),
+    #[error("rpc request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("malformed contract id or wasm hash: {0}")]
+    BadIdentifier(String),
+    #[error("unexpected rpc response: {0}")]
+    BadResponse(String),
+}
+
+#[derive(Deserialize)]
+struct RpcEnvelope<T> {
+    result: Option<T>,
+    error: Option<RpcErrorBody>,
+}
+
+#[derive(Deserialize)]
+struct RpcErrorBody {
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct GetLedgerEntriesResult {
+    entries: Vec<LedgerEntry>,
+}
+
+#[derive(Deserialize)]
+struct LedgerEntry {
+    /// Base64-encoded `LedgerEntryData` XDR for the requested key.
+    xdr: String,
+}
+
+impl StellarRpcClient {
+    pub fn new(mainnet_url: String, testnet_url: String, futurenet_url: String) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            mainnet_url,
+            testnet_url,
+            futurenet_url,
+        }
+    }
+
+    fn endpoint(&self, network: StellarNetwork) -> &str {
+        match network {
+            StellarNetwork::Mainnet => &self.mainnet_url,
+            StellarNetwork::Testnet => &self.testnet_url,
+            StellarNetwork::Futurenet => &self.futurenet_url,
+        }
+    }
+
+    async fn get_ledger_entry(
+        &self,
+        network: StellarNetwork,
+        key: &LedgerKey,
+        not_found_id: &str,
+    ) -> Result<LedgerEntryData, ChainError> {
+        let key_xdr = key
+            .to_xdr_base64(Limits::none())
+            .map_err(|e| ChainError::BadResponse(format!("failed to encode ledger key: {e}")))?;
+
+        let body = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "getLedgerEntries",
+            "params": {
+                "keys": [key_xdr],
+            },
+        });
+
+        let resp: RpcEnvelope<GetLedgerEntriesResult> = self
+            .http
+            .post(self.endpoint(network))
+            .json(&body)
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        if let Some(err) = resp.error {
+            return Err(ChainError::BadResponse(err.message));
+        }
+
+        let entries = resp
+            .result
+            .ok_or_else(|| ChainError::BadResponse("missing result".into()))?
+            .entries;
+
+        let entry = entries
+            .into_iter()
+            .next()
+            .ok_or_else(|| ChainError::ContractNotFound(not_found_id.to_string()))?;
+
+        LedgerEntryData::from_xdr_base64(entry.xdr, Limits::none())
+            .map_err(|e| ChainError::BadResponse(format!("failed to decode ledger entry: {e}")))
+    }
+
+    /// Fetches the contract instance ledger entry for `contract_id` on
+    /// `network` and returns the hash of the WASM it currently executes.
+    /// Returns `ChainError::ContractNotFound` if nothing is deployed there.
+    pub async fn fetch_deployed_wasm_hash(
+        &self,
+        contract_id: &str,
+        network: StellarNetwork,
+    ) -> Result<String, ChainError> {
+        let address = stellar_strkey::Contract::from_string(contract_id)
+            .map_err(|e| ChainError::BadIdentifier(e.to_string()))?;
+
+        let key = LedgerKey::ContractData(LedgerKeyContractData {
+            contract: ScAddress::Contract(Hash(address.0)),
+            key: ScVal::LedgerKeyContractInstance,
+            durability: ContractDataDurability::Persistent,
+        });
+
+        let entry = self.get_ledger_entry(network, &key, contract_id).await?;
+
+        let LedgerEntryData::ContractData(data) = entry else {
+            return Err(ChainError::BadResponse(
+                "expected ContractData ledger entry".into(),
+            ));
+        };
+
+        let ScVal::ContractInstance(instance) = data.val else {
+            return Err(ChainError::BadResponse(
+                "expected ScContractInstance value".into(),
+            ));
+        };
+
+        match instance.executable {
+            ContractExecutable::Wasm(hash) => Ok(hex::encode(hash.0)),
+            ContractExecutable::StellarAsset => Err(ChainError::BadResponse(
+                "contract is a Stellar Asset Contract, not a WASM contract".into(),
+            )),
+        }
+    }
+
+    /// Fetches the raw WASM bytecode stored under `wasm_hash` on `network`,
+    /// so callers (e.g. interface extraction at publish time) can inspect
+    /// the actual deployed module rather than just its hash.
+    pub async fn fetch_contract_code(
+        &self,
+        wasm_hash: &str,
+        network: StellarNetwork,
+    ) -> Result<Vec<u8>, ChainError> {
+        let hash_bytes: [u8; 32] = hex::decode(wasm_hash)
+            .map_err(|e| ChainError::BadIdentifier(e.to_string()))?
+            .try_into()
+            .map_err(|_| ChainError::BadIdentifier(format!("{wasm_hash} is not a 32-byte hash")))?;
+
+        let key = LedgerKey::ContractCode(LedgerKeyContractCode {
+            hash: Hash(hash_bytes),
+        });
+
+        let entry = self.get_ledger_entry(network, &key, wasm_hash).await?;
+
+        let LedgerEntryData::ContractCode(code) = entry else {
+            return Err(ChainError::BadResponse(
+                "expected ContractCode ledger entry".into(),
+            ));
+        };
+
+        Ok(code.code.to_vec())
+    }
+}
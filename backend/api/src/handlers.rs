@@ -3,13 +3,29 @@ use axum::{
     http::StatusCode,
     Json,
 };
+use serde::Deserialize;
 use shared::{
-    Contract, ContractSearchParams, ContractVersion, PaginatedResponse, 
+    Contract, ContractSearchParams, ContractVersion, PaginatedResponse,
     PublishRequest, Publisher, VerifyRequest,
 };
 use uuid::Uuid;
 
+use crate::auth::Signed;
+use crate::chain::StellarNetwork;
+use crate::interface;
 use crate::state::AppState;
+use crate::store::{NewContract, StoreError};
+
+/// Maps a storage-layer error to the HTTP status a handler should return.
+/// `NotFound` and `Conflict` carry enough meaning to pick a specific code;
+/// anything else is an opaque backend failure.
+fn store_err(err: StoreError) -> StatusCode {
+    match err {
+        StoreError::NotFound => StatusCode::NOT_FOUND,
+        StoreError::Conflict(_) => StatusCode::CONFLICT,
+        StoreError::Backend(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
 
 /// Health check — probes DB connectivity and reports uptime.
 /// Returns 200 when everything is reachable, 503 when the database
@@ -22,10 +38,7 @@ pub async fn health_check(
 
     // Quick connectivity probe — keeps the query as cheap as possible
     // so that frequent polling from orchestrators doesn't add load.
-    let db_ok = sqlx::query_scalar::<_, i32>("SELECT 1")
-        .fetch_one(&state.db)
-        .await
-        .is_ok();
+    let db_ok = state.store.health_probe().await;
 
     if db_ok {
         tracing::info!(uptime_secs = uptime, "health check passed");
@@ -58,76 +71,34 @@ pub async fn health_check(
 pub async fn get_stats(
     State(state): State<AppState>,
 ) -> Result<Json<serde_json::Value>, StatusCode> {
-    let total_contracts: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM contracts")
-        .fetch_one(&state.db)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-
-    let verified_contracts: i64 = sqlx::query_scalar(
-        "SELECT COUNT(*) FROM contracts WHERE is_verified = true"
-    )
-        .fetch_one(&state.db)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-
-    let total_publishers: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM publishers")
-        .fetch_one(&state.db)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let stats = state.store.stats().await.map_err(store_err)?;
 
     Ok(Json(serde_json::json!({
-        "total_contracts": total_contracts,
-        "verified_contracts": verified_contracts,
-        "total_publishers": total_publishers,
+        "total_contracts": stats.total_contracts,
+        "verified_contracts": stats.verified_contracts,
+        "total_publishers": stats.total_publishers,
     })))
 }
 
-/// List and search contracts
+/// List and search contracts.
+///
+/// Free-text search runs against the `search_vector` tsvector column (see
+/// migration `0002_contracts_fulltext.sql`) via `websearch_to_tsquery`, so it
+/// benefits from the GIN index instead of an unindexed `ILIKE` scan. The
+/// query itself lives in [`crate::store::postgres`] — this handler only
+/// orchestrates pagination over whatever `RegistryStore` is configured.
 pub async fn list_contracts(
     State(state): State<AppState>,
     Query(params): Query<ContractSearchParams>,
 ) -> Result<Json<PaginatedResponse<Contract>>, StatusCode> {
     let page = params.page.unwrap_or(1).max(1);
     let page_size = params.page_size.unwrap_or(20).min(100);
-    let offset = (page - 1) * page_size;
-
-    // Build dynamic query based on filters
-    let mut query = String::from("SELECT * FROM contracts WHERE 1=1");
-    let mut count_query = String::from("SELECT COUNT(*) FROM contracts WHERE 1=1");
-
-    if let Some(ref q) = params.query {
-        let search_clause = format!(
-            " AND (name ILIKE '%{}%' OR description ILIKE '%{}%')",
-            q, q
-        );
-        query.push_str(&search_clause);
-        count_query.push_str(&search_clause);
-    }
-
-    if let Some(verified) = params.verified_only {
-        if verified {
-            query.push_str(" AND is_verified = true");
-            count_query.push_str(" AND is_verified = true");
-        }
-    }
 
-    if let Some(ref category) = params.category {
-        let category_clause = format!(" AND category = '{}'", category);
-        query.push_str(&category_clause);
-        count_query.push_str(&category_clause);
-    }
-
-    query.push_str(&format!(" ORDER BY created_at DESC LIMIT {} OFFSET {}", page_size, offset));
-
-    let contracts: Vec<Contract> = sqlx::query_as(&query)
-        .fetch_all(&state.db)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-
-    let total: i64 = sqlx::query_scalar(&count_query)
-        .fetch_one(&state.db)
+    let (contracts, total) = state
+        .store
+        .list_contracts(&params)
         .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        .map_err(store_err)?;
 
     Ok(Json(PaginatedResponse::new(contracts, total, page, page_size)))
 }
@@ -137,103 +108,373 @@ pub async fn get_contract(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<Contract>, StatusCode> {
-    let contract: Contract = sqlx::query_as(
-        "SELECT * FROM contracts WHERE id = $1"
-    )
-        .bind(id)
-        .fetch_one(&state.db)
-        .await
-        .map_err(|_| StatusCode::NOT_FOUND)?;
-
+    let contract = state.store.get_contract(id).await.map_err(store_err)?;
     Ok(Json(contract))
 }
 
-/// Get contract version history
+#[derive(Deserialize)]
+pub struct IncludeYankedQuery {
+    include_yanked: Option<bool>,
+}
+
+/// Get contract version history, newest first. Yanked versions are
+/// included by default; pass `?include_yanked=false` to resolve "latest
+/// non-yanked" deterministically.
 pub async fn get_contract_versions(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
+    Query(params): Query<IncludeYankedQuery>,
 ) -> Result<Json<Vec<ContractVersion>>, StatusCode> {
-    let versions: Vec<ContractVersion> = sqlx::query_as(
-        "SELECT * FROM contract_versions WHERE contract_id = $1 ORDER BY created_at DESC"
-    )
-        .bind(id)
-        .fetch_all(&state.db)
+    let include_yanked = params.include_yanked.unwrap_or(true);
+
+    let versions = state
+        .store
+        .get_contract_versions(id, include_yanked)
         .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        .map_err(store_err)?;
 
     Ok(Json(versions))
 }
 
-/// Publish a new contract
+/// Validates a newly published version string against the contract's
+/// existing history: it must parse as semver, must not duplicate an
+/// existing `(contract_id, version)` pair, and must be strictly greater
+/// than every previously published version.
+fn validate_new_version(
+    existing: &[ContractVersion],
+    version: &str,
+) -> Result<(), StatusCode> {
+    let candidate = semver::Version::parse(version).map_err(|_| StatusCode::BAD_REQUEST)?;
+
+    if existing.iter().any(|v| v.version == version) {
+        return Err(StatusCode::CONFLICT);
+    }
+
+    let latest = existing
+        .iter()
+        .filter_map(|v| semver::Version::parse(&v.version).ok())
+        .max();
+
+    if let Some(latest) = latest {
+        if candidate <= latest {
+            return Err(StatusCode::CONFLICT);
+        }
+    }
+
+    Ok(())
+}
+
+/// Confirms `address` owns the publisher of record for contract `id`,
+/// returning 403 otherwise. Shared by every mutating endpoint that acts on
+/// an already-registered contract rather than the caller's own profile.
+async fn require_owns_contract(
+    state: &AppState,
+    id: Uuid,
+    address: &str,
+) -> Result<(), StatusCode> {
+    let contract = state.store.get_contract(id).await.map_err(store_err)?;
+    let publisher = state
+        .store
+        .get_publisher(contract.publisher_id)
+        .await
+        .map_err(store_err)?;
+
+    if publisher.stellar_address != address {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    Ok(())
+}
+
+/// Yanks a published version, flagging it as withdrawn without deleting
+/// it — existing consumers pinned to it keep working, but fresh resolution
+/// of "latest" skips it.
+///
+/// Requires a `Signed` envelope from the contract's own publisher — without
+/// an ownership check here, anyone could yank (or unyank) any contract's
+/// versions.
+pub async fn yank_version(
+    State(state): State<AppState>,
+    Path((id, version)): Path<(Uuid, String)>,
+    Signed { address, .. }: Signed<()>,
+) -> Result<Json<ContractVersion>, StatusCode> {
+    require_owns_contract(&state, id, &address).await?;
+
+    let updated = state
+        .store
+        .set_version_yanked(id, &version, true)
+        .await
+        .map_err(store_err)?;
+
+    Ok(Json(updated))
+}
+
+/// Clears a version's yanked flag. Same ownership requirement as
+/// [`yank_version`].
+pub async fn unyank_version(
+    State(state): State<AppState>,
+    Path((id, version)): Path<(Uuid, String)>,
+    Signed { address, .. }: Signed<()>,
+) -> Result<Json<ContractVersion>, StatusCode> {
+    require_owns_contract(&state, id, &address).await?;
+
+    let updated = state
+        .store
+        .set_version_yanked(id, &version, false)
+        .await
+        .map_err(store_err)?;
+
+    Ok(Json(updated))
+}
+
+/// Publish a contract, either registering it for the first time or adding
+/// a new version to one that's already registered.
+///
+/// Requires a `Signed` envelope: the caller must sign over the request
+/// with the secret key for `req.publisher_address`, so only that key's
+/// holder can publish under it. The claimed contract is then looked up on
+/// chain before anything is persisted — a `contract_id` that doesn't
+/// resolve on the given `network` is rejected rather than recorded with a
+/// placeholder hash, so every published row reflects a WASM hash the
+/// chain actually served.
+///
+/// A `contract_id` that's already registered is treated as a republish:
+/// the existing contract row's `wasm_hash` is updated to the newly
+/// deployed one and a new row is added to its version history, subject to
+/// the same semver validation as any other version. A republish is only
+/// accepted from the contract's original publisher — proving the caller
+/// owns `req.publisher_address` says nothing about whether they own *this*
+/// contract. Version validation runs before any contract or version row
+/// is written (the publisher row is upserted first), and the
+/// `wasm_hash` update and version insert happen as a single atomic
+/// operation, so a rejected version can never leave a contract's
+/// `wasm_hash` pointing at a version that was never recorded.
 pub async fn publish_contract(
     State(state): State<AppState>,
-    Json(req): Json<PublishRequest>,
+    Signed { address, body: req }: Signed<PublishRequest>,
 ) -> Result<Json<Contract>, StatusCode> {
-    // First, ensure publisher exists or create one
-    let publisher: Publisher = sqlx::query_as(
-        "INSERT INTO publishers (stellar_address) VALUES ($1)
-         ON CONFLICT (stellar_address) DO UPDATE SET stellar_address = EXCLUDED.stellar_address
-         RETURNING *"
-    )
-        .bind(&req.publisher_address)
-        .fetch_one(&state.db)
+    if address != req.publisher_address {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    let network = StellarNetwork::parse(&req.network).ok_or(StatusCode::BAD_REQUEST)?;
+
+    let wasm_hash = state
+        .chain
+        .fetch_deployed_wasm_hash(&req.contract_id, network)
         .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-
-    // TODO: Fetch WASM hash from Stellar network
-    let wasm_hash = "placeholder_hash".to_string();
-
-    // Insert contract
-    let contract: Contract = sqlx::query_as(
-        "INSERT INTO contracts (contract_id, wasm_hash, name, description, publisher_id, network, category, tags)
-         VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
-         RETURNING *"
-    )
-        .bind(&req.contract_id)
-        .bind(&wasm_hash)
-        .bind(&req.name)
-        .bind(&req.description)
-        .bind(publisher.id)
-        .bind(&req.network)
-        .bind(&req.category)
-        .bind(&req.tags)
-        .fetch_one(&state.db)
+        .map_err(|err| {
+            tracing::warn!(contract_id = %req.contract_id, %err, "on-chain lookup failed");
+            StatusCode::UNPROCESSABLE_ENTITY
+        })?;
+
+    // First, ensure publisher exists or create one
+    let publisher = state
+        .store
+        .upsert_publisher(&req.publisher_address)
         .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        .map_err(store_err)?;
+
+    let contract = match state.store.get_contract_by_contract_id(&req.contract_id).await {
+        Ok(existing) => {
+            if existing.publisher_id != publisher.id {
+                return Err(StatusCode::FORBIDDEN);
+            }
+
+            let existing_versions = state
+                .store
+                .get_contract_versions(existing.id, true)
+                .await
+                .map_err(store_err)?;
+            validate_new_version(&existing_versions, &req.version)?;
+
+            state
+                .store
+                .publish_new_version(existing.id, &req.version, &wasm_hash)
+                .await
+                .map_err(store_err)?;
+
+            Contract {
+                wasm_hash: wasm_hash.clone(),
+                ..existing
+            }
+        }
+        Err(StoreError::NotFound) => {
+            // No existing versions to check against, but the version
+            // string still has to parse as semver before anything is
+            // written.
+            validate_new_version(&[], &req.version)?;
+
+            let created = state
+                .store
+                .insert_contract(NewContract {
+                    contract_id: req.contract_id.clone(),
+                    wasm_hash: wasm_hash.clone(),
+                    name: req.name,
+                    description: req.description,
+                    publisher_id: publisher.id,
+                    network: req.network,
+                    category: req.category,
+                    tags: req.tags,
+                })
+                .await
+                .map_err(store_err)?;
+
+            state
+                .store
+                .insert_version(created.id, &req.version, &wasm_hash)
+                .await
+                .map_err(store_err)?;
+
+            created
+        }
+        Err(other) => return Err(store_err(other)),
+    };
+
+    // Best-effort: a contract without a decodable interface is still a
+    // valid publish, it just won't show up in interface-based discovery.
+    match state.chain.fetch_contract_code(&wasm_hash, network).await {
+        Ok(wasm_bytes) => match interface::parse_contract_spec(&wasm_bytes) {
+            Ok(parsed) => {
+                if let Ok(json) = serde_json::to_string(&parsed) {
+                    if let Err(err) = state.store.upsert_interface(&wasm_hash, &json).await {
+                        tracing::warn!(%wasm_hash, %err, "failed to persist contract interface");
+                    }
+                }
+            }
+            Err(err) => {
+                tracing::warn!(%wasm_hash, %err, "failed to decode contract interface");
+            }
+        },
+        Err(err) => {
+            tracing::warn!(%wasm_hash, %err, "failed to fetch contract code for interface extraction");
+        }
+    }
 
     Ok(Json(contract))
 }
 
-/// Verify a contract
+/// Returns the decoded interface (callable functions and UDTs) for a
+/// contract's currently published WASM, as extracted from its
+/// `contractspecv0` section at publish time.
+pub async fn get_contract_interface(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<serde_json::Value>, StatusCode> {
+    let contract = state.store.get_contract(id).await.map_err(store_err)?;
+
+    let spec_json = state
+        .store
+        .get_interface(&contract.wasm_hash)
+        .await
+        .map_err(store_err)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let value: serde_json::Value =
+        serde_json::from_str(&spec_json).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(value))
+}
+
+/// Resolves the WASM bytes a verification request submitted, either by
+/// downloading `wasm_url` or taking the inline `wasm_bytes`.
+async fn fetch_submitted_wasm(
+    state: &AppState,
+    req: &VerifyRequest,
+) -> Result<Vec<u8>, StatusCode> {
+    match &req.wasm_url {
+        Some(url) => state
+            .http
+            .get(url)
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|_| StatusCode::BAD_REQUEST)?
+            .bytes()
+            .await
+            .map(|b| b.to_vec())
+            .map_err(|_| StatusCode::BAD_REQUEST),
+        None => req.wasm_bytes.clone().ok_or(StatusCode::BAD_REQUEST),
+    }
+}
+
+/// Verify a contract by comparing a submitted WASM artifact against what's
+/// actually deployed on chain.
+///
+/// The contract's current `wasm_hash` (populated from the chain at publish
+/// time) is the source of truth; the submitted blob's SHA-256 either
+/// matches it, doesn't, or the blob itself can't be fetched, and all three
+/// outcomes are recorded in `verifications`. `get_contract` does not read
+/// that table; it reports only the `is_verified` flag.
 pub async fn verify_contract(
-    State(_state): State<AppState>,
-    Json(_req): Json<VerifyRequest>,
+    State(state): State<AppState>,
+    Json(req): Json<VerifyRequest>,
 ) -> Result<Json<serde_json::Value>, StatusCode> {
-    // TODO: Implement verification logic
+    use sha2::{Digest, Sha256};
+
+    let contract = state
+        .store
+        .get_contract(req.contract_id)
+        .await
+        .map_err(store_err)?;
+
+    let wasm_bytes = match fetch_submitted_wasm(&state, &req).await {
+        Ok(bytes) => bytes,
+        Err(status) => {
+            // The submitted artifact couldn't even be fetched, but that's
+            // still a verification attempt worth recording — otherwise
+            // "failed" never shows up in `verifications` at all.
+            if let Err(err) = state.store.record_verification(contract.id, "failed", "").await {
+                tracing::warn!(%err, "failed to record failed verification attempt");
+            }
+            return Err(status);
+        }
+    };
+
+    let computed_hash = hex::encode(Sha256::digest(&wasm_bytes));
+    let outcome = if computed_hash == contract.wasm_hash {
+        "verified"
+    } else {
+        "mismatch"
+    };
+
+    state
+        .store
+        .record_verification(contract.id, outcome, &computed_hash)
+        .await
+        .map_err(store_err)?;
+
+    if outcome == "verified" {
+        state
+            .store
+            .mark_verified(contract.id)
+            .await
+            .map_err(store_err)?;
+    }
+
     Ok(Json(serde_json::json!({
-        "status": "pending",
-        "message": "Verification started"
+        "status": outcome,
+        "computed_hash": computed_hash,
     })))
 }
 
-/// Create a publisher
+/// Create a publisher.
+///
+/// Requires a `Signed` envelope over the submitted `stellar_address` —
+/// without it anyone could register a publisher profile under an address
+/// they don't control.
 pub async fn create_publisher(
     State(state): State<AppState>,
-    Json(publisher): Json<Publisher>,
+    Signed { address, body: publisher }: Signed<Publisher>,
 ) -> Result<Json<Publisher>, StatusCode> {
-    let created: Publisher = sqlx::query_as(
-        "INSERT INTO publishers (stellar_address, username, email, github_url, website)
-         VALUES ($1, $2, $3, $4, $5)
-         RETURNING *"
-    )
-        .bind(&publisher.stellar_address)
-        .bind(&publisher.username)
-        .bind(&publisher.email)
-        .bind(&publisher.github_url)
-        .bind(&publisher.website)
-        .fetch_one(&state.db)
+    if address != publisher.stellar_address {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    let created = state
+        .store
+        .insert_publisher(publisher)
         .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        .map_err(store_err)?;
 
     Ok(Json(created))
 }
@@ -243,14 +484,7 @@ pub async fn get_publisher(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<Publisher>, StatusCode> {
-    let publisher: Publisher = sqlx::query_as(
-        "SELECT * FROM publishers WHERE id = $1"
-    )
-        .bind(id)
-        .fetch_one(&state.db)
-        .await
-        .map_err(|_| StatusCode::NOT_FOUND)?;
-
+    let publisher = state.store.get_publisher(id).await.map_err(store_err)?;
     Ok(Json(publisher))
 }
 
@@ -259,13 +493,159 @@ pub async fn get_publisher_contracts(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<Vec<Contract>>, StatusCode> {
-    let contracts: Vec<Contract> = sqlx::query_as(
-        "SELECT * FROM contracts WHERE publisher_id = $1 ORDER BY created_at DESC"
-    )
-        .bind(id)
-        .fetch_all(&state.db)
+    let contracts = state
+        .store
+        .get_publisher_contracts(id)
         .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        .map_err(store_err)?;
 
     Ok(Json(contracts))
 }
+
+/// Handler-level tests wired through a real `axum::Router` and `AppState`
+/// backed by [`crate::store::memory::MemoryStore`], so this suite runs
+/// without a live Postgres — the whole point `MemoryStore` was introduced
+/// for in #chunk0-4, but that it actually ran was never checked in.
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use axum::routing::get;
+    use axum::Router;
+    use tower::ServiceExt;
+
+    use crate::auth::NonceCache;
+    use crate::metrics::Metrics;
+    use crate::state::AppState;
+    use crate::store::memory::MemoryStore;
+    use crate::store::NewContract;
+
+    use super::*;
+
+    fn test_state() -> AppState {
+        AppState {
+            store: Arc::new(MemoryStore::new()),
+            chain: crate::chain::StellarRpcClient::new(
+                "https://mainnet.example".into(),
+                "https://testnet.example".into(),
+                "https://futurenet.example".into(),
+            ),
+            http: reqwest::Client::new(),
+            metrics: Arc::new(Metrics::new()),
+            nonce_cache: Arc::new(NonceCache::new()),
+            started_at: Instant::now(),
+        }
+    }
+
+    fn test_router(state: AppState) -> Router {
+        Router::new()
+            .route("/health", get(health_check))
+            .route("/contracts", get(list_contracts))
+            .route("/contracts/:id", get(get_contract))
+            .route("/publishers/:id/contracts", get(get_publisher_contracts))
+            .with_state(state)
+    }
+
+    async fn get_body_json(response: axum::response::Response) -> serde_json::Value {
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn health_check_reports_ok_when_store_is_healthy() {
+        let app = test_router(test_state());
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/health")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = get_body_json(response).await;
+        assert_eq!(body["status"], "ok");
+    }
+
+    #[tokio::test]
+    async fn get_contract_returns_404_for_unknown_id() {
+        let app = test_router(test_state());
+        let missing = Uuid::new_v4();
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/contracts/{missing}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn list_contracts_returns_a_contract_inserted_directly_through_the_store() {
+        let state = test_state();
+        let publisher = state.store.upsert_publisher("GABC123").await.unwrap();
+        state
+            .store
+            .insert_contract(NewContract {
+                contract_id: "CABC123".into(),
+                wasm_hash: "deadbeef".into(),
+                name: "example".into(),
+                description: None,
+                publisher_id: publisher.id,
+                network: "testnet".into(),
+                category: None,
+                tags: vec![],
+            })
+            .await
+            .unwrap();
+
+        let app = test_router(state);
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/contracts")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = get_body_json(response).await;
+        assert_eq!(body["items"][0]["contract_id"], "CABC123");
+        assert_eq!(body["total"], 1);
+    }
+
+    #[tokio::test]
+    async fn get_publisher_contracts_returns_empty_list_for_unknown_publisher() {
+        let app = test_router(test_state());
+        let missing = Uuid::new_v4();
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/publishers/{missing}/contracts"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = get_body_json(response).await;
+        assert_eq!(body, serde_json::json!([]));
+    }
+}
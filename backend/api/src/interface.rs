@@ -0,0 +1,426 @@
+//! Decodes the Soroban `contractspecv0` custom WASM section into structured
+//! function/type definitions, so the registry can answer "what can this
+//! contract do" instead of just "what is it called".
+//!
+//! The section is a concatenation of XDR-encoded `SCSpecEntry` values (one
+//! per exported function or user-defined type). This module decodes them
+//! with a hand-written reader for the small XDR subset they use, rather
+//! than through `stellar-xdr`'s generated types.
+
+const WASM_CUSTOM_SECTION_NAME: &str = "contractspecv0";
+
+#[derive(Debug, thiserror::Error)]
+pub enum InterfaceError {
+    #[error("no contractspecv0 section found in WASM")]
+    SectionMissing,
+    #[error("malformed WASM module: {0}")]
+    InvalidWasm(String),
+    #[error("malformed spec entry: {0}")]
+    InvalidSpec(String),
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct FunctionSpec {
+    pub name: String,
+    pub inputs: Vec<NamedType>,
+    pub outputs: Vec<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct NamedType {
+    pub name: String,
+    pub type_name: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct UdtSpec {
+    pub name: String,
+    pub kind: &'static str,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct ContractInterface {
+    pub functions: Vec<FunctionSpec>,
+    pub types: Vec<UdtSpec>,
+}
+
+/// Extracts and decodes the `contractspecv0` custom section from a
+/// contract's WASM bytes.
+pub fn parse_contract_spec(wasm_bytes: &[u8]) -> Result<ContractInterface, InterfaceError> {
+    let section = find_custom_section(wasm_bytes, WASM_CUSTOM_SECTION_NAME)
+        .ok_or(InterfaceError::SectionMissing)?;
+
+    let mut reader = XdrReader::new(section);
+    let mut interface = ContractInterface::default();
+
+    while reader.remaining() > 0 {
+        match reader.read_u32()? {
+            0 => interface.functions.push(read_function_v0(&mut reader)?),
+            1 => interface.types.push(read_named_udt(&mut reader, "struct")?),
+            2 => interface.types.push(read_named_udt(&mut reader, "union")?),
+            3 => interface.types.push(read_named_udt(&mut reader, "enum")?),
+            4 => interface.types.push(read_named_udt(&mut reader, "error_enum")?),
+            other => {
+                return Err(InterfaceError::InvalidSpec(format!(
+                    "unknown SCSpecEntry discriminant {other}"
+                )))
+            }
+        }
+    }
+
+    Ok(interface)
+}
+
+/// Walks a WASM module's section headers looking for a custom section
+/// named `name`, returning its payload bytes.
+fn find_custom_section<'a>(wasm_bytes: &'a [u8], name: &str) -> Option<&'a [u8]> {
+    // WASM magic (0x00 0x61 0x73 0x6d) + version (4 bytes) precede sections.
+    const HEADER_LEN: usize = 8;
+    if wasm_bytes.len() < HEADER_LEN || &wasm_bytes[0..4] != b"\0asm" {
+        return None;
+    }
+
+    let mut offset = HEADER_LEN;
+    while offset < wasm_bytes.len() {
+        let section_id = wasm_bytes[offset];
+        offset += 1;
+        let (section_len, len_bytes) = read_leb128(&wasm_bytes[offset..])?;
+        offset += len_bytes;
+        let section_end = offset + section_len as usize;
+        if section_end > wasm_bytes.len() {
+            return None;
+        }
+
+        if section_id == 0 {
+            // Custom section: a name string followed by the payload.
+            let body = &wasm_bytes[offset..section_end];
+            let (name_len, name_len_bytes) = read_leb128(body)?;
+            let name_start = name_len_bytes;
+            let name_end = name_start + name_len as usize;
+            if name_end > body.len() {
+                return None;
+            }
+            if &body[name_start..name_end] == name.as_bytes() {
+                return Some(&body[name_end..]);
+            }
+        }
+
+        offset = section_end;
+    }
+
+    None
+}
+
+/// Minimal unsigned LEB128 reader, as used for WASM section lengths.
+fn read_leb128(bytes: &[u8]) -> Option<(u64, usize)> {
+    let mut result: u64 = 0;
+    let mut shift = 0;
+    for (i, &byte) in bytes.iter().enumerate() {
+        result |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some((result, i + 1));
+        }
+        shift += 7;
+    }
+    None
+}
+
+/// Big-endian XDR cursor over the decoded custom section bytes.
+struct XdrReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> XdrReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn remaining(&self) -> usize {
+        self.bytes.len().saturating_sub(self.pos)
+    }
+
+    fn read_u32(&mut self) -> Result<u32, InterfaceError> {
+        if self.remaining() < 4 {
+            return Err(InterfaceError::InvalidSpec("unexpected end of spec".into()));
+        }
+        let value = u32::from_be_bytes(self.bytes[self.pos..self.pos + 4].try_into().unwrap());
+        self.pos += 4;
+        Ok(value)
+    }
+
+    /// XDR strings/opaque data: a u32 length, the bytes, then padding to
+    /// the next 4-byte boundary.
+    fn read_string(&mut self) -> Result<String, InterfaceError> {
+        let len = self.read_u32()? as usize;
+        if self.remaining() < len {
+            return Err(InterfaceError::InvalidSpec("truncated string".into()));
+        }
+        let raw = &self.bytes[self.pos..self.pos + len];
+        let value = String::from_utf8_lossy(raw).into_owned();
+        self.pos += len;
+        self.pos += (4 - len % 4) % 4;
+        Ok(value)
+    }
+
+    /// Decodes an `SCSpecTypeDef`. Compound variants (`Option`, `Result`,
+    /// `Vec`, `Map`, `Tuple`, `BytesN`) recurse into their nested type defs
+    /// so every byte they occupy is consumed — leaving any of them
+    /// un-consumed would desync the cursor for every entry that follows.
+    fn read_type_def(&mut self) -> Result<String, InterfaceError> {
+        const SC_SPEC_TYPE_OPTION: u32 = 1000;
+        const SC_SPEC_TYPE_RESULT: u32 = 1001;
+        const SC_SPEC_TYPE_VEC: u32 = 1002;
+        const SC_SPEC_TYPE_MAP: u32 = 1003;
+        const SC_SPEC_TYPE_TUPLE: u32 = 1004;
+        const SC_SPEC_TYPE_BYTES_N: u32 = 1005;
+        const SC_SPEC_TYPE_UDT: u32 = 2000;
+
+        match self.read_u32()? {
+            SC_SPEC_TYPE_OPTION => Ok(format!("Option<{}>", self.read_type_def()?)),
+            SC_SPEC_TYPE_RESULT => {
+                let ok_type = self.read_type_def()?;
+                let error_type = self.read_type_def()?;
+                Ok(format!("Result<{ok_type}, {error_type}>"))
+            }
+            SC_SPEC_TYPE_VEC => Ok(format!("Vec<{}>", self.read_type_def()?)),
+            SC_SPEC_TYPE_MAP => {
+                let key_type = self.read_type_def()?;
+                let value_type = self.read_type_def()?;
+                Ok(format!("Map<{key_type}, {value_type}>"))
+            }
+            SC_SPEC_TYPE_TUPLE => {
+                let count = self.read_u32()?;
+                let mut parts = Vec::with_capacity(count as usize);
+                for _ in 0..count {
+                    parts.push(self.read_type_def()?);
+                }
+                Ok(format!("({})", parts.join(", ")))
+            }
+            SC_SPEC_TYPE_BYTES_N => Ok(format!("BytesN<{}>", self.read_u32()?)),
+            SC_SPEC_TYPE_UDT => self.read_string(),
+            scalar => Ok(scalar_type_name(scalar)),
+        }
+    }
+}
+
+/// Labels the fixed-size `SCSpecTypeDef` variants that carry no further
+/// data on the wire (`bool`, `u32`, ...). Falls back to the raw
+/// discriminant for anything this module doesn't name explicitly, which is
+/// safe since none of those variants have payload bytes to skip either.
+fn scalar_type_name(discriminant: u32) -> String {
+    match discriminant {
+        0 => "val",
+        1 => "bool",
+        2 => "void",
+        3 => "error",
+        4 => "u32",
+        5 => "i32",
+        6 => "u64",
+        7 => "i64",
+        8 => "timepoint",
+        9 => "duration",
+        10 => "u128",
+        11 => "i128",
+        12 => "u256",
+        13 => "i256",
+        14 => "bytes",
+        16 => "string",
+        17 => "symbol",
+        19 => "address",
+        20 => "muxed_address",
+        other => return format!("scalar#{other}"),
+    }
+    .to_string()
+}
+
+fn read_function_v0(reader: &mut XdrReader<'_>) -> Result<FunctionSpec, InterfaceError> {
+    // Doc comment (string) precedes the name in SCSpecFunctionV0.
+    let _doc = reader.read_string()?;
+    let name = reader.read_string()?;
+
+    let input_count = reader.read_u32()?;
+    let mut inputs = Vec::with_capacity(input_count as usize);
+    for _ in 0..input_count {
+        let _doc = reader.read_string()?;
+        let name = reader.read_string()?;
+        let type_name = reader.read_type_def()?;
+        inputs.push(NamedType { name, type_name });
+    }
+
+    let output_count = reader.read_u32()?;
+    let mut outputs = Vec::with_capacity(output_count as usize);
+    for _ in 0..output_count {
+        outputs.push(reader.read_type_def()?);
+    }
+
+    Ok(FunctionSpec {
+        name,
+        inputs,
+        outputs,
+    })
+}
+
+/// Decodes an `SCSpecUDT{Struct,Union,Enum,ErrorEnum}V0` entry. The summary
+/// returned only needs the UDT's name, but every field/case byte still has
+/// to be read off the cursor — leaving them unread would desync parsing of
+/// every entry that follows this one in the spec section.
+fn read_named_udt(reader: &mut XdrReader<'_>, kind: &'static str) -> Result<UdtSpec, InterfaceError> {
+    let _doc = reader.read_string()?;
+    let _lib = reader.read_string()?;
+    let name = reader.read_string()?;
+
+    match kind {
+        "struct" => {
+            let field_count = reader.read_u32()?;
+            for _ in 0..field_count {
+                let _doc = reader.read_string()?; // SCSpecUDTStructFieldV0.doc
+                let _field_name = reader.read_string()?;
+                let _field_type = reader.read_type_def()?;
+            }
+        }
+        "union" => {
+            let case_count = reader.read_u32()?;
+            for _ in 0..case_count {
+                // SCSpecUDTUnionCaseV0 is itself a union: 0 = VoidV0 (doc,
+                // name), 1 = TupleV0 (doc, name, Vec<SCSpecTypeDef>).
+                let case_kind = reader.read_u32()?;
+                let _doc = reader.read_string()?;
+                let _case_name = reader.read_string()?;
+                if case_kind == 1 {
+                    let type_count = reader.read_u32()?;
+                    for _ in 0..type_count {
+                        reader.read_type_def()?;
+                    }
+                }
+            }
+        }
+        "enum" | "error_enum" => {
+            let case_count = reader.read_u32()?;
+            for _ in 0..case_count {
+                let _doc = reader.read_string()?;
+                let _case_name = reader.read_string()?;
+                let _value = reader.read_u32()?;
+            }
+        }
+        other => {
+            return Err(InterfaceError::InvalidSpec(format!(
+                "unknown UDT kind {other}"
+            )))
+        }
+    }
+
+    Ok(UdtSpec { name, kind })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// XDR-encodes a string as a u32 length followed by the bytes, padded
+    /// to a 4-byte boundary — mirrors what `XdrReader::read_string` expects.
+    fn xdr_string(s: &str) -> Vec<u8> {
+        let mut out = (s.len() as u32).to_be_bytes().to_vec();
+        out.extend_from_slice(s.as_bytes());
+        out.resize(out.len() + (4 - s.len() % 4) % 4, 0);
+        out
+    }
+
+    /// Minimal unsigned LEB128 encoder, mirroring `read_leb128`.
+    fn write_leb128(mut value: u64) -> Vec<u8> {
+        let mut out = Vec::new();
+        loop {
+            let mut byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value != 0 {
+                byte |= 0x80;
+            }
+            out.push(byte);
+            if value == 0 {
+                break;
+            }
+        }
+        out
+    }
+
+    fn wrap_custom_section(name: &str, payload: &[u8]) -> Vec<u8> {
+        let mut section_body = write_leb128(name.len() as u64);
+        section_body.extend_from_slice(name.as_bytes());
+        section_body.extend_from_slice(payload);
+
+        let mut wasm = b"\0asm".to_vec();
+        wasm.extend_from_slice(&[1, 0, 0, 0]); // version
+        wasm.push(0); // custom section id
+        wasm.extend(write_leb128(section_body.len() as u64));
+        wasm.extend_from_slice(&section_body);
+        wasm
+    }
+
+    #[test]
+    fn parses_a_single_function_with_no_args() {
+        let mut entry = 0u32.to_be_bytes().to_vec(); // SCSpecEntry::FunctionV0
+        entry.extend(xdr_string("")); // doc
+        entry.extend(xdr_string("initialize")); // name
+        entry.extend(0u32.to_be_bytes()); // input count
+        entry.extend(0u32.to_be_bytes()); // output count
+
+        let wasm = wrap_custom_section(WASM_CUSTOM_SECTION_NAME, &entry);
+        let interface = parse_contract_spec(&wasm).unwrap();
+
+        assert_eq!(interface.functions.len(), 1);
+        assert_eq!(interface.functions[0].name, "initialize");
+        assert!(interface.functions[0].inputs.is_empty());
+    }
+
+    #[test]
+    fn missing_section_is_reported() {
+        let wasm = wrap_custom_section("some_other_section", &[]);
+        let result = parse_contract_spec(&wasm);
+        assert!(matches!(result, Err(InterfaceError::SectionMissing)));
+    }
+
+    /// A function taking `Option<Vec<u32>>` followed immediately by a
+    /// struct with two fields — this is the regression case: if the
+    /// compound type def or the struct's fields aren't fully consumed, the
+    /// struct's discriminant gets misread as garbage and parsing fails or
+    /// silently drops the rest of the spec.
+    #[test]
+    fn parses_compound_types_and_a_multi_field_struct_without_desyncing() {
+        let mut function_entry = 0u32.to_be_bytes().to_vec(); // SCSpecEntry::FunctionV0
+        function_entry.extend(xdr_string("")); // doc
+        function_entry.extend(xdr_string("transfer")); // name
+        function_entry.extend(1u32.to_be_bytes()); // input count
+        function_entry.extend(xdr_string("")); // input doc
+        function_entry.extend(xdr_string("amounts")); // input name
+        function_entry.extend(1000u32.to_be_bytes()); // SC_SPEC_TYPE_OPTION
+        function_entry.extend(1002u32.to_be_bytes()); // SC_SPEC_TYPE_VEC
+        function_entry.extend(4u32.to_be_bytes()); // SC_SPEC_TYPE_U32
+        function_entry.extend(0u32.to_be_bytes()); // output count
+
+        let mut struct_entry = 1u32.to_be_bytes().to_vec(); // SCSpecEntry::UDTStructV0
+        struct_entry.extend(xdr_string("")); // doc
+        struct_entry.extend(xdr_string("")); // lib
+        struct_entry.extend(xdr_string("Balance")); // name
+        struct_entry.extend(2u32.to_be_bytes()); // field count
+        struct_entry.extend(xdr_string("")); // field 1 doc
+        struct_entry.extend(xdr_string("amount")); // field 1 name
+        struct_entry.extend(10u32.to_be_bytes()); // SC_SPEC_TYPE_U128
+        struct_entry.extend(xdr_string("")); // field 2 doc
+        struct_entry.extend(xdr_string("owner")); // field 2 name
+        struct_entry.extend(19u32.to_be_bytes()); // SC_SPEC_TYPE_ADDRESS
+
+        let mut payload = function_entry;
+        payload.extend(struct_entry);
+
+        let wasm = wrap_custom_section(WASM_CUSTOM_SECTION_NAME, &payload);
+        let interface = parse_contract_spec(&wasm).unwrap();
+
+        assert_eq!(interface.functions.len(), 1);
+        assert_eq!(interface.functions[0].name, "transfer");
+        assert_eq!(interface.functions[0].inputs[0].type_name, "Option<Vec<u32>>");
+
+        assert_eq!(interface.types.len(), 1);
+        assert_eq!(interface.types[0].name, "Balance");
+        assert_eq!(interface.types[0].kind, "struct");
+    }
+}
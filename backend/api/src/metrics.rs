@@ -0,0 +1,177 @@
+//! Observability layer: a `GET /metrics` endpoint in Prometheus text
+//! format, fed by middleware that times every request and a background
+//! refresh of the registry-domain gauges backing [`handlers::get_stats`].
+
+use std::time::Instant;
+
+use axum::{
+    extract::{MatchedPath, Request, State},
+    middleware::Next,
+    response::IntoResponse,
+};
+use prometheus::{
+    HistogramVec, IntCounterVec, IntGauge, Registry, TextEncoder,
+};
+
+use crate::state::AppState;
+
+/// Registry of all metrics the API exposes, held in `AppState` so both the
+/// request-timing middleware and the periodic stats refresh can record
+/// into the same collectors that `GET /metrics` serializes.
+pub struct Metrics {
+    registry: Registry,
+    pub http_requests_total: IntCounterVec,
+    pub http_request_duration_seconds: HistogramVec,
+    pub db_query_duration_seconds: HistogramVec,
+    pub health_check_up: IntGauge,
+    pub contracts_total: IntGauge,
+    pub contracts_verified_total: IntGauge,
+    pub publishers_total: IntGauge,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        let registry = Registry::new();
+
+        let http_requests_total = IntCounterVec::new(
+            prometheus::Opts::new("http_requests_total", "Total HTTP requests handled"),
+            &["method", "path", "status"],
+        )
+        .expect("metric names are static and valid");
+
+        let http_request_duration_seconds = HistogramVec::new(
+            prometheus::HistogramOpts::new(
+                "http_request_duration_seconds",
+                "HTTP request latency in seconds",
+            ),
+            &["method", "path"],
+        )
+        .expect("metric names are static and valid");
+
+        let db_query_duration_seconds = HistogramVec::new(
+            prometheus::HistogramOpts::new(
+                "db_query_duration_seconds",
+                "Database query latency in seconds",
+            ),
+            &["operation"],
+        )
+        .expect("metric names are static and valid");
+
+        let health_check_up = IntGauge::new("health_check_up", "1 if the last DB probe succeeded")
+            .expect("metric names are static and valid");
+        let contracts_total = IntGauge::new("registry_contracts_total", "Total contracts registered")
+            .expect("metric names are static and valid");
+        let contracts_verified_total = IntGauge::new(
+            "registry_contracts_verified_total",
+            "Contracts with a verified WASM hash",
+        )
+        .expect("metric names are static and valid");
+        let publishers_total = IntGauge::new("registry_publishers_total", "Total publishers registered")
+            .expect("metric names are static and valid");
+
+        for collector in [
+            Box::new(http_requests_total.clone()) as Box<dyn prometheus::core::Collector>,
+            Box::new(http_request_duration_seconds.clone()),
+            Box::new(db_query_duration_seconds.clone()),
+            Box::new(health_check_up.clone()),
+            Box::new(contracts_total.clone()),
+            Box::new(contracts_verified_total.clone()),
+            Box::new(publishers_total.clone()),
+        ] {
+            registry
+                .register(collector)
+                .expect("collector is only registered once");
+        }
+
+        Self {
+            registry,
+            http_requests_total,
+            http_request_duration_seconds,
+            db_query_duration_seconds,
+            health_check_up,
+            contracts_total,
+            contracts_verified_total,
+            publishers_total,
+        }
+    }
+
+    fn encode(&self) -> String {
+        let encoder = TextEncoder::new();
+        let families = self.registry.gather();
+        encoder
+            .encode_to_string(&families)
+            .unwrap_or_else(|_| String::new())
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `GET /metrics` — renders the registry in Prometheus text exposition
+/// format for scraping.
+pub async fn metrics_handler(State(state): State<AppState>) -> impl IntoResponse {
+    (
+        [("content-type", "text/plain; version=0.0.4")],
+        state.metrics.encode(),
+    )
+}
+
+/// Middleware recording a request count and latency observation for every
+/// request, labeled by method, matched route *template* (e.g.
+/// `/contracts/:id`, not `/contracts/<uuid>`), and response status.
+///
+/// Must be installed as a `route_layer` (or otherwise run after routing),
+/// so `MatchedPath` is present in the request extensions by the time this
+/// runs. Falling back to the raw URI path here would give every distinct
+/// contract/version ID its own label series — unbounded cardinality growth
+/// in Prometheus.
+pub async fn track_request_metrics(
+    State(state): State<AppState>,
+    req: Request,
+    next: Next,
+) -> impl IntoResponse {
+    let method = req.method().to_string();
+    let path = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|matched| matched.as_str().to_string())
+        .unwrap_or_else(|| "unmatched".to_string());
+    let start = Instant::now();
+
+    let response = next.run(req).await;
+
+    let status = response.status().as_u16().to_string();
+    state
+        .metrics
+        .http_requests_total
+        .with_label_values(&[&method, &path, &status])
+        .inc();
+    state
+        .metrics
+        .http_request_duration_seconds
+        .with_label_values(&[&method, &path])
+        .observe(start.elapsed().as_secs_f64());
+
+    response
+}
+
+/// Refreshes the registry-domain gauges (contract/publisher counts and the
+/// health-probe gauge) from the current `RegistryStore` state. Intended to
+/// be called on a timer from the server's startup wiring, alongside the
+/// existing `health_check` polling.
+pub async fn refresh_domain_gauges(state: &AppState) {
+    let probe_ok = state.store.health_probe().await;
+    state.metrics.health_check_up.set(probe_ok as i64);
+
+    if let Ok(stats) = state.store.stats().await {
+        state.metrics.contracts_total.set(stats.total_contracts);
+        state
+            .metrics
+            .contracts_verified_total
+            .set(stats.verified_contracts);
+        state.metrics.publishers_total.set(stats.total_publishers);
+    }
+}
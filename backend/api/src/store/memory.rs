@@ -0,0 +1,482 @@
+//! In-memory [`RegistryStore`] used by the handler test suite and for
+//! lightweight deployments that don't need a real Postgres instance.
+
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use shared::{Contract, ContractSearchParams, ContractVersion, Publisher};
+use uuid::Uuid;
+
+use super::{NewContract, RegistryStats, RegistryStore, StoreError};
+
+#[derive(Default)]
+struct Inner {
+    contracts: Vec<Contract>,
+    publishers: Vec<Publisher>,
+    versions: Vec<ContractVersion>,
+    interfaces: std::collections::HashMap<String, String>,
+}
+
+#[derive(Default)]
+pub struct MemoryStore {
+    inner: Mutex<Inner>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Inner> {
+        self.inner.lock().expect("MemoryStore mutex poisoned")
+    }
+}
+
+#[async_trait]
+impl RegistryStore for MemoryStore {
+    async fn list_contracts(
+        &self,
+        params: &ContractSearchParams,
+    ) -> Result<(Vec<Contract>, i64), StoreError> {
+        let inner = self.lock();
+        let page = params.page.unwrap_or(1).max(1);
+        let page_size = params.page_size.unwrap_or(20).min(100);
+
+        let filtered: Vec<Contract> = inner
+            .contracts
+            .iter()
+            .filter(|c| {
+                params.query.as_ref().map_or(true, |q| {
+                    let q = q.to_lowercase();
+                    c.name.to_lowercase().contains(&q)
+                        || c.description
+                            .as_deref()
+                            .is_some_and(|d| d.to_lowercase().contains(&q))
+                })
+            })
+            .filter(|c| params.verified_only != Some(true) || c.is_verified)
+            .filter(|c| {
+                params
+                    .category
+                    .as_ref()
+                    .map_or(true, |cat| c.category.as_deref() == Some(cat.as_str()))
+            })
+            .filter(|c| {
+                params.function.as_ref().map_or(true, |function| {
+                    inner
+                        .interfaces
+                        .get(&c.wasm_hash)
+                        .and_then(|json| serde_json::from_str::<serde_json::Value>(json).ok())
+                        .and_then(|v| v.get("functions").cloned())
+                        .and_then(|fns| fns.as_array().cloned())
+                        .is_some_and(|fns| {
+                            fns.iter()
+                                .any(|f| f.get("name").and_then(|n| n.as_str()) == Some(function.as_str()))
+                        })
+                })
+            })
+            .cloned()
+            .collect();
+
+        let total = filtered.len() as i64;
+        let page = filtered
+            .into_iter()
+            .skip(((page - 1) * page_size) as usize)
+            .take(page_size as usize)
+            .collect();
+
+        Ok((page, total))
+    }
+
+    async fn get_contract(&self, id: Uuid) -> Result<Contract, StoreError> {
+        self.lock()
+            .contracts
+            .iter()
+            .find(|c| c.id == id)
+            .cloned()
+            .ok_or(StoreError::NotFound)
+    }
+
+    async fn get_contract_by_contract_id(&self, contract_id: &str) -> Result<Contract, StoreError> {
+        self.lock()
+            .contracts
+            .iter()
+            .find(|c| c.contract_id == contract_id)
+            .cloned()
+            .ok_or(StoreError::NotFound)
+    }
+
+    async fn publish_new_version(
+        &self,
+        contract_id: Uuid,
+        version: &str,
+        wasm_hash: &str,
+    ) -> Result<ContractVersion, StoreError> {
+        let mut inner = self.lock();
+        if inner
+            .versions
+            .iter()
+            .any(|v| v.contract_id == contract_id && v.version == version)
+        {
+            return Err(StoreError::Conflict(format!(
+                "version {version} already published"
+            )));
+        }
+        if !inner.contracts.iter().any(|c| c.id == contract_id) {
+            return Err(StoreError::NotFound);
+        }
+
+        let row = ContractVersion {
+            id: Uuid::new_v4(),
+            contract_id,
+            version: version.to_string(),
+            wasm_hash: wasm_hash.to_string(),
+            yanked: false,
+            created_at: Utc::now(),
+        };
+        inner.versions.push(row.clone());
+
+        let contract = inner
+            .contracts
+            .iter_mut()
+            .find(|c| c.id == contract_id)
+            .expect("presence just checked above");
+        contract.wasm_hash = wasm_hash.to_string();
+
+        Ok(row)
+    }
+
+    async fn get_contract_versions(
+        &self,
+        id: Uuid,
+        include_yanked: bool,
+    ) -> Result<Vec<ContractVersion>, StoreError> {
+        Ok(self
+            .lock()
+            .versions
+            .iter()
+            // Versions are appended as they're published, so reversing
+            // yields newest first, matching the trait contract.
+            .rev()
+            .filter(|v| v.contract_id == id)
+            .filter(|v| include_yanked || !v.yanked)
+            .cloned()
+            .collect())
+    }
+
+    async fn insert_version(
+        &self,
+        contract_id: Uuid,
+        version: &str,
+        wasm_hash: &str,
+    ) -> Result<ContractVersion, StoreError> {
+        let mut inner = self.lock();
+        if inner
+            .versions
+            .iter()
+            .any(|v| v.contract_id == contract_id && v.version == version)
+        {
+            return Err(StoreError::Conflict(format!(
+                "version {version} already published"
+            )));
+        }
+
+        let row = ContractVersion {
+            id: Uuid::new_v4(),
+            contract_id,
+            version: version.to_string(),
+            wasm_hash: wasm_hash.to_string(),
+            yanked: false,
+            created_at: Utc::now(),
+        };
+        inner.versions.push(row.clone());
+        Ok(row)
+    }
+
+    async fn set_version_yanked(
+        &self,
+        contract_id: Uuid,
+        version: &str,
+        yanked: bool,
+    ) -> Result<ContractVersion, StoreError> {
+        let mut inner = self.lock();
+        let row = inner
+            .versions
+            .iter_mut()
+            .find(|v| v.contract_id == contract_id && v.version == version)
+            .ok_or(StoreError::NotFound)?;
+        row.yanked = yanked;
+        Ok(row.clone())
+    }
+
+    async fn insert_contract(&self, new_contract: NewContract) -> Result<Contract, StoreError> {
+        let mut inner = self.lock();
+        if inner
+            .contracts
+            .iter()
+            .any(|c| c.contract_id == new_contract.contract_id)
+        {
+            return Err(StoreError::Conflict("contract_id already exists".into()));
+        }
+
+        let contract = Contract {
+            id: Uuid::new_v4(),
+            contract_id: new_contract.contract_id,
+            wasm_hash: new_contract.wasm_hash,
+            name: new_contract.name,
+            description: new_contract.description,
+            publisher_id: new_contract.publisher_id,
+            network: new_contract.network,
+            category: new_contract.category,
+            tags: new_contract.tags,
+            is_verified: false,
+            created_at: Utc::now(),
+        };
+        inner.contracts.push(contract.clone());
+        Ok(contract)
+    }
+
+    async fn record_verification(
+        &self,
+        _contract_id: Uuid,
+        _outcome: &str,
+        _computed_hash: &str,
+    ) -> Result<(), StoreError> {
+        // Verification history isn't modeled in-memory; the test backend
+        // only needs to exercise the verified/unverified state transition.
+        Ok(())
+    }
+
+    async fn mark_verified(&self, contract_id: Uuid) -> Result<(), StoreError> {
+        let mut inner = self.lock();
+        let contract = inner
+            .contracts
+            .iter_mut()
+            .find(|c| c.id == contract_id)
+            .ok_or(StoreError::NotFound)?;
+        contract.is_verified = true;
+        Ok(())
+    }
+
+    async fn upsert_publisher(&self, stellar_address: &str) -> Result<Publisher, StoreError> {
+        let mut inner = self.lock();
+        if let Some(existing) = inner
+            .publishers
+            .iter()
+            .find(|p| p.stellar_address == stellar_address)
+        {
+            return Ok(existing.clone());
+        }
+
+        let publisher = Publisher {
+            id: Uuid::new_v4(),
+            stellar_address: stellar_address.to_string(),
+            username: None,
+            email: None,
+            github_url: None,
+            website: None,
+            created_at: Utc::now(),
+        };
+        inner.publishers.push(publisher.clone());
+        Ok(publisher)
+    }
+
+    async fn insert_publisher(&self, mut publisher: Publisher) -> Result<Publisher, StoreError> {
+        let mut inner = self.lock();
+        if inner
+            .publishers
+            .iter()
+            .any(|p| p.stellar_address == publisher.stellar_address)
+        {
+            return Err(StoreError::Conflict("stellar_address already registered".into()));
+        }
+        publisher.id = Uuid::new_v4();
+        publisher.created_at = Utc::now();
+        inner.publishers.push(publisher.clone());
+        Ok(publisher)
+    }
+
+    async fn get_publisher(&self, id: Uuid) -> Result<Publisher, StoreError> {
+        self.lock()
+            .publishers
+            .iter()
+            .find(|p| p.id == id)
+            .cloned()
+            .ok_or(StoreError::NotFound)
+    }
+
+    async fn get_publisher_contracts(&self, id: Uuid) -> Result<Vec<Contract>, StoreError> {
+        Ok(self
+            .lock()
+            .contracts
+            .iter()
+            .filter(|c| c.publisher_id == id)
+            .cloned()
+            .collect())
+    }
+
+    async fn stats(&self) -> Result<RegistryStats, StoreError> {
+        let inner = self.lock();
+        Ok(RegistryStats {
+            total_contracts: inner.contracts.len() as i64,
+            verified_contracts: inner.contracts.iter().filter(|c| c.is_verified).count() as i64,
+            total_publishers: inner.publishers.len() as i64,
+        })
+    }
+
+    async fn health_probe(&self) -> bool {
+        true
+    }
+
+    async fn upsert_interface(&self, wasm_hash: &str, interface_json: &str) -> Result<(), StoreError> {
+        self.lock()
+            .interfaces
+            .insert(wasm_hash.to_string(), interface_json.to_string());
+        Ok(())
+    }
+
+    async fn get_interface(&self, wasm_hash: &str) -> Result<Option<String>, StoreError> {
+        Ok(self.lock().interfaces.get(wasm_hash).cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn sample_contract(store: &MemoryStore, contract_id: &str, publisher_id: Uuid) -> Contract {
+        store
+            .insert_contract(NewContract {
+                contract_id: contract_id.to_string(),
+                wasm_hash: "deadbeef".to_string(),
+                name: "Test Contract".to_string(),
+                description: Some("a test fixture".to_string()),
+                publisher_id,
+                network: "testnet".to_string(),
+                category: Some("defi".to_string()),
+                tags: vec!["test".to_string()],
+            })
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn insert_and_get_contract_round_trips() {
+        let store = MemoryStore::new();
+        let publisher = store.upsert_publisher("GABC123").await.unwrap();
+        let created = sample_contract(&store, "CABC123", publisher.id).await;
+
+        let fetched = store.get_contract(created.id).await.unwrap();
+        assert_eq!(fetched.contract_id, "CABC123");
+    }
+
+    #[tokio::test]
+    async fn publish_new_version_records_the_version_and_updates_wasm_hash() {
+        let store = MemoryStore::new();
+        let publisher = store.upsert_publisher("GABC123").await.unwrap();
+        let created = sample_contract(&store, "CABC123", publisher.id).await;
+
+        let found = store.get_contract_by_contract_id("CABC123").await.unwrap();
+        assert_eq!(found.id, created.id);
+
+        let version = store
+            .publish_new_version(created.id, "1.1.0", "newhash")
+            .await
+            .unwrap();
+        assert_eq!(version.version, "1.1.0");
+        assert_eq!(version.wasm_hash, "newhash");
+
+        let updated = store.get_contract(created.id).await.unwrap();
+        assert_eq!(updated.wasm_hash, "newhash");
+    }
+
+    #[tokio::test]
+    async fn publish_new_version_rejects_a_duplicate_version() {
+        let store = MemoryStore::new();
+        let publisher = store.upsert_publisher("GABC123").await.unwrap();
+        let created = sample_contract(&store, "CABC123", publisher.id).await;
+
+        store
+            .publish_new_version(created.id, "1.0.0", "deadbeef")
+            .await
+            .unwrap();
+        let result = store.publish_new_version(created.id, "1.0.0", "deadbeef").await;
+
+        assert!(matches!(result, Err(StoreError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn duplicate_contract_id_is_rejected() {
+        let store = MemoryStore::new();
+        let publisher = store.upsert_publisher("GABC123").await.unwrap();
+        sample_contract(&store, "CABC123", publisher.id).await;
+
+        let result = store
+            .insert_contract(NewContract {
+                contract_id: "CABC123".to_string(),
+                wasm_hash: "deadbeef".to_string(),
+                name: "Dupe".to_string(),
+                description: None,
+                publisher_id: publisher.id,
+                network: "testnet".to_string(),
+                category: None,
+                tags: vec![],
+            })
+            .await;
+
+        assert!(matches!(result, Err(StoreError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn yanked_versions_are_excluded_when_requested() {
+        let store = MemoryStore::new();
+        let publisher = store.upsert_publisher("GABC123").await.unwrap();
+        let contract = sample_contract(&store, "CABC123", publisher.id).await;
+
+        store
+            .insert_version(contract.id, "1.0.0", "deadbeef")
+            .await
+            .unwrap();
+        store
+            .insert_version(contract.id, "1.1.0", "deadbeef")
+            .await
+            .unwrap();
+        store
+            .set_version_yanked(contract.id, "1.1.0", true)
+            .await
+            .unwrap();
+
+        let all = store.get_contract_versions(contract.id, true).await.unwrap();
+        assert_eq!(all.len(), 2);
+
+        let non_yanked = store
+            .get_contract_versions(contract.id, false)
+            .await
+            .unwrap();
+        assert_eq!(non_yanked.len(), 1);
+        assert_eq!(non_yanked[0].version, "1.0.0");
+    }
+
+    #[tokio::test]
+    async fn duplicate_version_is_rejected() {
+        let store = MemoryStore::new();
+        let publisher = store.upsert_publisher("GABC123").await.unwrap();
+        let contract = sample_contract(&store, "CABC123", publisher.id).await;
+
+        store
+            .insert_version(contract.id, "1.0.0", "deadbeef")
+            .await
+            .unwrap();
+        let result = store.insert_version(contract.id, "1.0.0", "deadbeef").await;
+
+        assert!(matches!(result, Err(StoreError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn get_missing_contract_returns_not_found() {
+        let store = MemoryStore::new();
+        let result = store.get_contract(Uuid::new_v4()).await;
+        assert!(matches!(result, Err(StoreError::NotFound)));
+    }
+}
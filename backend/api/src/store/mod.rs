@@ -0,0 +1,135 @@
+//! Storage abstraction for the registry.
+//!
+//! Handlers talk to `dyn RegistryStore` rather than a concrete `sqlx::PgPool`
+//! directly, so the HTTP layer doesn't know (or care) whether it's backed by
+//! Postgres or an in-memory store. [`postgres::PostgresStore`] is the
+//! production backend; [`memory::MemoryStore`] exists so the handler test
+//! suite and lightweight deployments don't need a live database.
+
+pub mod memory;
+pub mod postgres;
+
+use async_trait::async_trait;
+use shared::{Contract, ContractSearchParams, ContractVersion, Publisher};
+use uuid::Uuid;
+
+#[derive(Debug, thiserror::Error)]
+pub enum StoreError {
+    #[error("not found")]
+    NotFound,
+    #[error("conflict: {0}")]
+    Conflict(String),
+    #[error("backend error: {0}")]
+    Backend(String),
+}
+
+/// Fields required to insert a new contract row; mirrors `PublishRequest`
+/// plus the `wasm_hash` resolved from chain, which the store layer has no
+/// business computing itself.
+pub struct NewContract {
+    pub contract_id: String,
+    pub wasm_hash: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub publisher_id: Uuid,
+    pub network: String,
+    pub category: Option<String>,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct RegistryStats {
+    pub total_contracts: i64,
+    pub verified_contracts: i64,
+    pub total_publishers: i64,
+}
+
+/// Everything a handler needs from the data layer, independent of backend.
+#[async_trait]
+pub trait RegistryStore: Send + Sync {
+    async fn list_contracts(
+        &self,
+        params: &ContractSearchParams,
+    ) -> Result<(Vec<Contract>, i64), StoreError>;
+
+    async fn get_contract(&self, id: Uuid) -> Result<Contract, StoreError>;
+
+    /// Looks up a contract by its on-chain `contract_id` rather than its
+    /// registry-internal UUID. Used by `publish_contract` to detect that a
+    /// publish is actually a new version of an already-registered contract.
+    async fn get_contract_by_contract_id(&self, contract_id: &str) -> Result<Contract, StoreError>;
+
+    /// Records a new version for an already-registered contract and updates
+    /// its `wasm_hash` to match, atomically — callers must run semver
+    /// validation before calling this, since a failure partway through here
+    /// would otherwise risk leaving `wasm_hash` pointing at a version that
+    /// was never actually recorded in the version history.
+    async fn publish_new_version(
+        &self,
+        contract_id: Uuid,
+        version: &str,
+        wasm_hash: &str,
+    ) -> Result<ContractVersion, StoreError>;
+
+    /// Lists a contract's version history, newest first. Yanked versions
+    /// are included unless `include_yanked` is false.
+    async fn get_contract_versions(
+        &self,
+        id: Uuid,
+        include_yanked: bool,
+    ) -> Result<Vec<ContractVersion>, StoreError>;
+
+    /// Inserts a new `(contract_id, version)` row. Returns
+    /// `StoreError::Conflict` if that pair already exists — callers are
+    /// responsible for semver validation and monotonicity checks before
+    /// calling this, since those are registry policy, not storage concerns.
+    async fn insert_version(
+        &self,
+        contract_id: Uuid,
+        version: &str,
+        wasm_hash: &str,
+    ) -> Result<ContractVersion, StoreError>;
+
+    /// Flags (or clears) a published version as yanked without deleting it.
+    async fn set_version_yanked(
+        &self,
+        contract_id: Uuid,
+        version: &str,
+        yanked: bool,
+    ) -> Result<ContractVersion, StoreError>;
+
+    async fn insert_contract(&self, new_contract: NewContract) -> Result<Contract, StoreError>;
+
+    async fn record_verification(
+        &self,
+        contract_id: Uuid,
+        outcome: &str,
+        computed_hash: &str,
+    ) -> Result<(), StoreError>;
+
+    async fn mark_verified(&self, contract_id: Uuid) -> Result<(), StoreError>;
+
+    /// Inserts a publisher by Stellar address, or returns the existing row
+    /// if one is already registered under it.
+    async fn upsert_publisher(&self, stellar_address: &str) -> Result<Publisher, StoreError>;
+
+    async fn insert_publisher(&self, publisher: Publisher) -> Result<Publisher, StoreError>;
+
+    async fn get_publisher(&self, id: Uuid) -> Result<Publisher, StoreError>;
+
+    async fn get_publisher_contracts(&self, id: Uuid) -> Result<Vec<Contract>, StoreError>;
+
+    async fn stats(&self) -> Result<RegistryStats, StoreError>;
+
+    /// Cheap liveness probe used by `health_check`.
+    async fn health_probe(&self) -> bool;
+
+    /// Persists a contract's decoded interface, keyed by the WASM hash it
+    /// was extracted from (so republishing an identical binary reuses the
+    /// same row instead of duplicating it).
+    async fn upsert_interface(&self, wasm_hash: &str, interface_json: &str) -> Result<(), StoreError>;
+
+    /// Returns the decoded interface JSON for `wasm_hash`, if one has been
+    /// recorded.
+    async fn get_interface(&self, wasm_hash: &str) -> Result<Option<String>, StoreError>;
+}
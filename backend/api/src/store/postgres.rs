@@ -0,0 +1,448 @@
+//! Postgres-backed implementation of [`RegistryStore`], extracted from the
+//! handler layer so HTTP concerns and SQL concerns can evolve separately.
+//!
+//! Every query's latency is recorded in `db_query_duration_seconds`,
+//! labeled per operation: through [`PostgresStore::timed`], or by hand for
+//! the transaction in `publish_new_version`.
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use shared::{Contract, ContractSearchParams, ContractVersion, Publisher};
+use sqlx::{PgPool, Postgres, QueryBuilder};
+use uuid::Uuid;
+
+use crate::metrics::Metrics;
+
+use super::{NewContract, RegistryStats, RegistryStore, StoreError};
+
+#[derive(Clone)]
+pub struct PostgresStore {
+    pool: PgPool,
+    metrics: Arc<Metrics>,
+}
+
+impl PostgresStore {
+    pub fn new(pool: PgPool, metrics: Arc<Metrics>) -> Self {
+        Self { pool, metrics }
+    }
+
+    /// Runs `query`, recording its wall-clock time against
+    /// `db_query_duration_seconds` labeled by `operation`, regardless of
+    /// whether it succeeds. Every `RegistryStore` method except
+    /// `publish_new_version` (which times its whole transaction itself)
+    /// funnels its `sqlx` calls through here.
+    async fn timed<T, E>(&self, operation: &str, query: impl Future<Output = Result<T, E>>) -> Result<T, E> {
+        let start = Instant::now();
+        let result = query.await;
+        self.metrics
+            .db_query_duration_seconds
+            .with_label_values(&[operation])
+            .observe(start.elapsed().as_secs_f64());
+        result
+    }
+}
+
+/// Push the `query`/`verified_only`/`category`/`function` filters shared by the
+/// `list_contracts` result query and its companion count query, so the two
+/// can never drift out of sync. Every value is bound through `push_bind`
+/// rather than interpolated into the SQL string.
+fn apply_contract_filters(qb: &mut QueryBuilder<'_, Postgres>, params: &ContractSearchParams) {
+    if let Some(ref q) = params.query {
+        qb.push(" AND search_vector @@ websearch_to_tsquery('english', ");
+        qb.push_bind(q.clone());
+        qb.push(")");
+    }
+
+    if let Some(verified) = params.verified_only {
+        if verified {
+            qb.push(" AND is_verified = true");
+        }
+    }
+
+    if let Some(ref category) = params.category {
+        qb.push(" AND category = ");
+        qb.push_bind(category.clone());
+    }
+
+    if let Some(ref function) = params.function {
+        qb.push(
+            " AND EXISTS (
+                SELECT 1 FROM interfaces i, jsonb_array_elements(i.spec->'functions') AS fn
+                WHERE i.wasm_hash = contracts.wasm_hash AND fn->>'name' = "
+        );
+        qb.push_bind(function.clone());
+        qb.push(")");
+    }
+}
+
+fn map_sqlx_err(err: sqlx::Error) -> StoreError {
+    match err {
+        sqlx::Error::RowNotFound => StoreError::NotFound,
+        other => StoreError::Backend(other.to_string()),
+    }
+}
+
+#[async_trait]
+impl RegistryStore for PostgresStore {
+    async fn list_contracts(
+        &self,
+        params: &ContractSearchParams,
+    ) -> Result<(Vec<Contract>, i64), StoreError> {
+        let page = params.page.unwrap_or(1).max(1);
+        let page_size = params.page_size.unwrap_or(20).min(100);
+        let offset = (page - 1) * page_size;
+        let sort_by_relevance = params.query.is_some()
+            && params.sort.as_deref().unwrap_or("relevance") == "relevance";
+
+        let mut qb: QueryBuilder<Postgres> = QueryBuilder::new("SELECT *");
+        if sort_by_relevance {
+            if let Some(ref q) = params.query {
+                qb.push(", ts_rank(search_vector, websearch_to_tsquery('english', ");
+                qb.push_bind(q.clone());
+                qb.push(")) AS relevance");
+            }
+        }
+        qb.push(" FROM contracts WHERE 1=1");
+        apply_contract_filters(&mut qb, params);
+
+        if sort_by_relevance {
+            qb.push(" ORDER BY relevance DESC, created_at DESC");
+        } else {
+            qb.push(" ORDER BY created_at DESC");
+        }
+        qb.push(" LIMIT ").push_bind(page_size).push(" OFFSET ").push_bind(offset);
+
+        let contracts: Vec<Contract> = self
+            .timed("list_contracts", qb.build_query_as().fetch_all(&self.pool))
+            .await
+            .map_err(map_sqlx_err)?;
+
+        let mut count_qb: QueryBuilder<Postgres> =
+            QueryBuilder::new("SELECT COUNT(*) FROM contracts WHERE 1=1");
+        apply_contract_filters(&mut count_qb, params);
+
+        let total: i64 = self
+            .timed("list_contracts_count", count_qb.build_query_scalar().fetch_one(&self.pool))
+            .await
+            .map_err(map_sqlx_err)?;
+
+        Ok((contracts, total))
+    }
+
+    async fn get_contract(&self, id: Uuid) -> Result<Contract, StoreError> {
+        self.timed(
+            "get_contract",
+            sqlx::query_as("SELECT * FROM contracts WHERE id = $1")
+                .bind(id)
+                .fetch_one(&self.pool),
+        )
+        .await
+        .map_err(map_sqlx_err)
+    }
+
+    async fn get_contract_by_contract_id(&self, contract_id: &str) -> Result<Contract, StoreError> {
+        self.timed(
+            "get_contract_by_contract_id",
+            sqlx::query_as("SELECT * FROM contracts WHERE contract_id = $1")
+                .bind(contract_id)
+                .fetch_one(&self.pool),
+        )
+        .await
+        .map_err(map_sqlx_err)
+    }
+
+    async fn publish_new_version(
+        &self,
+        contract_id: Uuid,
+        version: &str,
+        wasm_hash: &str,
+    ) -> Result<ContractVersion, StoreError> {
+        let start = Instant::now();
+
+        let mut tx = self.pool.begin().await.map_err(map_sqlx_err)?;
+
+        let version_row: ContractVersion = sqlx::query_as(
+            "INSERT INTO contract_versions (contract_id, version, wasm_hash, yanked)
+             VALUES ($1, $2, $3, false)
+             RETURNING *"
+        )
+            .bind(contract_id)
+            .bind(version)
+            .bind(wasm_hash)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|err| match err {
+                sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => {
+                    StoreError::Conflict(format!("version {version} already published"))
+                }
+                other => map_sqlx_err(other),
+            })?;
+
+        sqlx::query("UPDATE contracts SET wasm_hash = $1 WHERE id = $2")
+            .bind(wasm_hash)
+            .bind(contract_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(map_sqlx_err)?;
+
+        tx.commit().await.map_err(map_sqlx_err)?;
+
+        self.metrics
+            .db_query_duration_seconds
+            .with_label_values(&["publish_new_version"])
+            .observe(start.elapsed().as_secs_f64());
+
+        Ok(version_row)
+    }
+
+    async fn get_contract_versions(
+        &self,
+        id: Uuid,
+        include_yanked: bool,
+    ) -> Result<Vec<ContractVersion>, StoreError> {
+        let mut qb: QueryBuilder<Postgres> =
+            QueryBuilder::new("SELECT * FROM contract_versions WHERE contract_id = ");
+        qb.push_bind(id);
+        if !include_yanked {
+            qb.push(" AND yanked = false");
+        }
+        qb.push(" ORDER BY created_at DESC");
+
+        self.timed("get_contract_versions", qb.build_query_as().fetch_all(&self.pool))
+            .await
+            .map_err(map_sqlx_err)
+    }
+
+    async fn insert_version(
+        &self,
+        contract_id: Uuid,
+        version: &str,
+        wasm_hash: &str,
+    ) -> Result<ContractVersion, StoreError> {
+        self.timed(
+            "insert_version",
+            sqlx::query_as(
+                "INSERT INTO contract_versions (contract_id, version, wasm_hash, yanked)
+                 VALUES ($1, $2, $3, false)
+                 RETURNING *"
+            )
+                .bind(contract_id)
+                .bind(version)
+                .bind(wasm_hash)
+                .fetch_one(&self.pool),
+        )
+        .await
+        .map_err(|err| match err {
+            sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => {
+                StoreError::Conflict(format!("version {version} already published"))
+            }
+            other => map_sqlx_err(other),
+        })
+    }
+
+    async fn set_version_yanked(
+        &self,
+        contract_id: Uuid,
+        version: &str,
+        yanked: bool,
+    ) -> Result<ContractVersion, StoreError> {
+        self.timed(
+            "set_version_yanked",
+            sqlx::query_as(
+                "UPDATE contract_versions SET yanked = $1
+                 WHERE contract_id = $2 AND version = $3
+                 RETURNING *"
+            )
+                .bind(yanked)
+                .bind(contract_id)
+                .bind(version)
+                .fetch_one(&self.pool),
+        )
+        .await
+        .map_err(map_sqlx_err)
+    }
+
+    async fn insert_contract(&self, new_contract: NewContract) -> Result<Contract, StoreError> {
+        self.timed(
+            "insert_contract",
+            sqlx::query_as(
+                "INSERT INTO contracts (contract_id, wasm_hash, name, description, publisher_id, network, category, tags)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                 RETURNING *"
+            )
+                .bind(&new_contract.contract_id)
+                .bind(&new_contract.wasm_hash)
+                .bind(&new_contract.name)
+                .bind(&new_contract.description)
+                .bind(new_contract.publisher_id)
+                .bind(&new_contract.network)
+                .bind(&new_contract.category)
+                .bind(&new_contract.tags)
+                .fetch_one(&self.pool),
+        )
+        .await
+        .map_err(map_sqlx_err)
+    }
+
+    async fn record_verification(
+        &self,
+        contract_id: Uuid,
+        outcome: &str,
+        computed_hash: &str,
+    ) -> Result<(), StoreError> {
+        self.timed(
+            "record_verification",
+            sqlx::query(
+                "INSERT INTO verifications (contract_id, outcome, computed_hash, checked_at)
+                 VALUES ($1, $2, $3, now())"
+            )
+                .bind(contract_id)
+                .bind(outcome)
+                .bind(computed_hash)
+                .execute(&self.pool),
+        )
+        .await
+        .map_err(map_sqlx_err)?;
+        Ok(())
+    }
+
+    async fn mark_verified(&self, contract_id: Uuid) -> Result<(), StoreError> {
+        self.timed(
+            "mark_verified",
+            sqlx::query("UPDATE contracts SET is_verified = true WHERE id = $1")
+                .bind(contract_id)
+                .execute(&self.pool),
+        )
+        .await
+        .map_err(map_sqlx_err)?;
+        Ok(())
+    }
+
+    async fn upsert_publisher(&self, stellar_address: &str) -> Result<Publisher, StoreError> {
+        self.timed(
+            "upsert_publisher",
+            sqlx::query_as(
+                "INSERT INTO publishers (stellar_address) VALUES ($1)
+                 ON CONFLICT (stellar_address) DO UPDATE SET stellar_address = EXCLUDED.stellar_address
+                 RETURNING *"
+            )
+                .bind(stellar_address)
+                .fetch_one(&self.pool),
+        )
+        .await
+        .map_err(map_sqlx_err)
+    }
+
+    async fn insert_publisher(&self, publisher: Publisher) -> Result<Publisher, StoreError> {
+        self.timed(
+            "insert_publisher",
+            sqlx::query_as(
+                "INSERT INTO publishers (stellar_address, username, email, github_url, website)
+                 VALUES ($1, $2, $3, $4, $5)
+                 RETURNING *"
+            )
+                .bind(&publisher.stellar_address)
+                .bind(&publisher.username)
+                .bind(&publisher.email)
+                .bind(&publisher.github_url)
+                .bind(&publisher.website)
+                .fetch_one(&self.pool),
+        )
+        .await
+        .map_err(map_sqlx_err)
+    }
+
+    async fn get_publisher(&self, id: Uuid) -> Result<Publisher, StoreError> {
+        self.timed(
+            "get_publisher",
+            sqlx::query_as("SELECT * FROM publishers WHERE id = $1")
+                .bind(id)
+                .fetch_one(&self.pool),
+        )
+        .await
+        .map_err(map_sqlx_err)
+    }
+
+    async fn get_publisher_contracts(&self, id: Uuid) -> Result<Vec<Contract>, StoreError> {
+        self.timed(
+            "get_publisher_contracts",
+            sqlx::query_as("SELECT * FROM contracts WHERE publisher_id = $1 ORDER BY created_at DESC")
+                .bind(id)
+                .fetch_all(&self.pool),
+        )
+        .await
+        .map_err(map_sqlx_err)
+    }
+
+    async fn stats(&self) -> Result<RegistryStats, StoreError> {
+        let total_contracts: i64 = self
+            .timed(
+                "stats_total_contracts",
+                sqlx::query_scalar("SELECT COUNT(*) FROM contracts").fetch_one(&self.pool),
+            )
+            .await
+            .map_err(map_sqlx_err)?;
+
+        let verified_contracts: i64 = self
+            .timed(
+                "stats_verified_contracts",
+                sqlx::query_scalar("SELECT COUNT(*) FROM contracts WHERE is_verified = true")
+                    .fetch_one(&self.pool),
+            )
+            .await
+            .map_err(map_sqlx_err)?;
+
+        let total_publishers: i64 = self
+            .timed(
+                "stats_total_publishers",
+                sqlx::query_scalar("SELECT COUNT(*) FROM publishers").fetch_one(&self.pool),
+            )
+            .await
+            .map_err(map_sqlx_err)?;
+
+        Ok(RegistryStats {
+            total_contracts,
+            verified_contracts,
+            total_publishers,
+        })
+    }
+
+    async fn health_probe(&self) -> bool {
+        self.timed(
+            "health_probe",
+            sqlx::query_scalar::<_, i32>("SELECT 1").fetch_one(&self.pool),
+        )
+        .await
+        .is_ok()
+    }
+
+    async fn upsert_interface(&self, wasm_hash: &str, interface_json: &str) -> Result<(), StoreError> {
+        self.timed(
+            "upsert_interface",
+            sqlx::query(
+                "INSERT INTO interfaces (wasm_hash, spec) VALUES ($1, $2::jsonb)
+                 ON CONFLICT (wasm_hash) DO UPDATE SET spec = EXCLUDED.spec"
+            )
+                .bind(wasm_hash)
+                .bind(interface_json)
+                .execute(&self.pool),
+        )
+        .await
+        .map_err(map_sqlx_err)?;
+        Ok(())
+    }
+
+    async fn get_interface(&self, wasm_hash: &str) -> Result<Option<String>, StoreError> {
+        self.timed(
+            "get_interface",
+            sqlx::query_scalar("SELECT spec::text FROM interfaces WHERE wasm_hash = $1")
+                .bind(wasm_hash)
+                .fetch_optional(&self.pool),
+        )
+        .await
+        .map_err(map_sqlx_err)
+    }
+}